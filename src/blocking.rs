@@ -1,11 +1,43 @@
+//! Synchronous mirror of the (default, async) device-discovery API.
+//!
+//! Everything here re-implements an async counterpart (most of them in
+//! [`crate::find`]) using blocking `std::fs` calls instead of `tokio::fs`, for callers that have no
+//! async runtime to hand. It is only compiled with the `blocking` feature,
+//! since the async API is canonical.
+//!
+//! Gating this module behind a feature flag keeps it from being built (and
+//! so from bit-rotting) for the common case where nobody asked for it, but
+//! it does NOT remove the hand-duplication itself: `list_devfs`,
+//! `is_furiosa_device`, `read_mgmt_files`, `list_devices_with`,
+//! `get_device_with`, `get_device_file_with`, `expand_status`,
+//! `get_status_all`, `get_device_status`, and `acquire` each have a sync
+//! copy here that must be kept in step by hand with its async counterpart
+//! (`list_devices_with` in [`crate::list`], `get_status_all` as a `Device`
+//! method, most of the rest in [`crate::find`]). This request only delivers the feature-gating half of
+//! its goal; the drift itself is still live, and this series has already
+//! demonstrated the cost twice — the `is_furiosa_device` check in
+//! `get_device_with` and the `acquire` reservation both landed in one copy
+//! several commits before they were ported to the other.
+//!
+//! Actually removing the duplication would need a shared I/O trait (e.g. a
+//! `Filesystem` abstraction with blocking and tokio implementations) that
+//! `crate::find`'s functions are generic over, so there is exactly one copy
+//! of each function body. That's a real option, not a hypothetical one, but
+//! it touches every function in both modules and is out of scope for this
+//! request; it should be scoped as its own follow-up rather than folded in
+//! here.
+#![cfg(feature = "blocking")]
+
 use std::collections::HashMap;
-use std::fs::OpenOptions;
+use std::fs::{File, OpenOptions};
 use std::io;
 use std::path::Path;
 
 use crate::device::{CoreIdx, CoreStatus, DeviceInfo};
+use crate::error::DeviceError;
 use crate::find::DeviceWithStatus;
 use crate::list::{collect_devices, filter_dev_files, DevFile, MGMT_FILES};
+use crate::parse::parse_indices;
 use crate::status::DeviceStatus;
 use crate::sysfs::npu_mgmt;
 use crate::sysfs::npu_mgmt::PLATFORM_TYPE;
@@ -20,6 +52,49 @@ pub fn find_devices(config: &DeviceConfig) -> DeviceResult<Vec<DeviceFile>> {
     find_devices_in(config, &devices)
 }
 
+/// Fetches a single device by its index, e.g. `1` for `npu1`.
+pub fn get_device(idx: u8) -> DeviceResult<Device> {
+    get_device_with(idx, "/dev", "/sys")
+}
+
+pub(crate) fn get_device_with(idx: u8, devfs: &str, sysfs: &str) -> DeviceResult<Device> {
+    let paths = filter_dev_files(list_devfs(devfs)?)?
+        .into_iter()
+        .find(|(i, _)| *i == idx)
+        .map(|(_, paths)| paths)
+        .ok_or_else(|| DeviceError::device_not_found(format!("npu{idx}")))?;
+
+    if !is_furiosa_device(idx, sysfs) {
+        return Err(DeviceError::device_not_found(format!("npu{idx}")));
+    }
+
+    let mgmt_files = read_mgmt_files(sysfs, idx)?;
+    let device_info = DeviceInfo::try_from(mgmt_files)?;
+    collect_devices(idx, device_info, paths)
+}
+
+/// Fetches a single device file by its name, e.g. `npu0`, `npu0pe0`, or
+/// `npu0pe0-1`.
+pub fn get_device_file(name: &str) -> DeviceResult<DeviceFile> {
+    get_device_file_with(name, "/dev", "/sys")
+}
+
+pub(crate) fn get_device_file_with(
+    name: &str,
+    devfs: &str,
+    sysfs: &str,
+) -> DeviceResult<DeviceFile> {
+    let (idx, _cores) = parse_indices(name)?;
+    let device = get_device_with(idx, devfs, sysfs)?;
+
+    device
+        .dev_files()
+        .iter()
+        .find(|dev_file| dev_file.filename() == name)
+        .cloned()
+        .ok_or_else(|| DeviceError::device_not_found(name))
+}
+
 /// Allow to specify arbitrary sysfs, devfs paths for unit testing
 pub(crate) fn list_devices_with(devfs: &str, sysfs: &str) -> DeviceResult<Vec<Device>> {
     let npu_dev_files = filter_dev_files(list_devfs(devfs)?)?;
@@ -119,6 +194,103 @@ pub fn get_status_all(device: &Device) -> DeviceResult<HashMap<CoreIdx, CoreStat
     Ok(status_map)
 }
 
+/// A [`DeviceFile`] that has been opened and reserved for exclusive use.
+///
+/// Dropping an `OpenDeviceFile` closes the underlying handle, releasing the
+/// device back to other callers.
+pub struct OpenDeviceFile {
+    dev_file: DeviceFile,
+    #[allow(dead_code)]
+    file: File,
+}
+
+impl OpenDeviceFile {
+    pub fn filename(&self) -> &str {
+        self.dev_file.filename()
+    }
+
+    pub fn device_file(&self) -> &DeviceFile {
+        &self.dev_file
+    }
+}
+
+/// Selects devices suitable for `config` and opens each one as soon as it's
+/// selected, turning [`find_devices`]'s advisory snapshot into a real
+/// reservation. An `EBUSY` at open time means the core was claimed by
+/// someone else between selection and open; rather than propagating that as
+/// an error, selection simply retries against the remaining candidates.
+/// A [`DeviceConfig::named`] config has no other candidates, so an `EBUSY`
+/// on one of its files returns an empty vec.
+///
+/// Follows the same all-or-nothing convention as [`find_devices`]/
+/// [`find_devices_in`]: if the requested count can't be satisfied, an empty
+/// vec is returned and anything opened along the way is released.
+pub fn acquire(config: &DeviceConfig) -> DeviceResult<Vec<OpenDeviceFile>> {
+    let mut devices = expand_status(list_devices()?)?;
+    let mut opened: Vec<OpenDeviceFile> = Vec::with_capacity(config.count() as usize);
+
+    while opened.len() < config.count() as usize {
+        let probe = if config.is_named() {
+            config.clone()
+        } else {
+            config.with_count((config.count() as usize - opened.len()) as u8)
+        };
+
+        let candidates = find_devices_in(&probe, &devices)?;
+        if candidates.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        for dev_file in candidates {
+            mark_occupied(&mut devices, &dev_file)?;
+
+            match try_open(&dev_file) {
+                Ok(file) => opened.push(OpenDeviceFile { dev_file, file }),
+                Err(err) if err.raw_os_error().unwrap_or(0) == 16 => {
+                    if config.is_named() {
+                        // a fixed named target has no fallback candidate
+                        return Ok(Vec::new());
+                    }
+                    // otherwise, loop around: find_devices_in will see the
+                    // refreshed (now-occupied) status and pick a different
+                    // candidate for this slot.
+                }
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    Ok(opened)
+}
+
+fn try_open(dev_file: &DeviceFile) -> io::Result<File> {
+    OpenOptions::new().read(true).write(true).open(&dev_file.path)
+}
+
+/// Marks `dev_file`'s cores as occupied in `devices`' status snapshot, so a
+/// subsequent [`find_devices_in`] call doesn't try to select it again.
+fn mark_occupied(devices: &mut [DeviceWithStatus], dev_file: &DeviceFile) -> DeviceResult<()> {
+    let (device_idx, _cores) = parse_indices(dev_file.filename())?;
+
+    if let Some(device) = devices
+        .iter_mut()
+        .find(|device| device.device_index() == device_idx)
+    {
+        let cores: Vec<CoreIdx> = if dev_file.is_multicore() {
+            device.cores().to_vec()
+        } else {
+            dev_file.indices().to_vec()
+        };
+        for core in cores {
+            device
+                .statuses
+                .insert(core, CoreStatus::Occupied(dev_file.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +329,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_get_device() -> DeviceResult<()> {
+        let device = get_device_with(0, "test_data/test-0/dev", "test_data/test-0/sys")?;
+        assert_eq!(device.device_index(), 0);
+
+        // an index with no matching npu device should fail
+        assert!(get_device_with(9, "test_data/test-0/dev", "test_data/test-0/sys").is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_device_rejects_non_furiosa_platform() {
+        // npu2 has a /dev entry but its sysfs platform_type file doesn't
+        // identify it as a Furiosa device, mirroring the filter
+        // list_devices_with applies; get_device should reject it the same
+        // way rather than returning it.
+        assert!(get_device_with(2, "test_data/test-0/dev", "test_data/test-0/sys").is_err());
+    }
+
+    #[test]
+    fn test_get_device_file() -> DeviceResult<()> {
+        let dev_file =
+            get_device_file_with("npu0pe0-1", "test_data/test-0/dev", "test_data/test-0/sys")?;
+        assert_eq!(dev_file.filename(), "npu0pe0-1");
+
+        // a device that doesn't exist should fail
+        assert!(
+            get_device_file_with("npu9pe0", "test_data/test-0/dev", "test_data/test-0/sys")
+                .is_err()
+        );
+
+        // a core that doesn't exist on an otherwise valid device should fail
+        assert!(
+            get_device_file_with("npu0pe9", "test_data/test-0/dev", "test_data/test-0/sys")
+                .is_err()
+        );
+
+        Ok(())
+    }
 }
@@ -1,15 +1,25 @@
 use std::collections::{HashMap, HashSet};
+use std::io;
 use std::ops::Deref;
+use std::path::Path;
+
+use tokio::fs;
+use tokio::fs::{File, OpenOptions};
 
 use crate::arch::Arch;
-use crate::device::{CoreIdx, CoreStatus, Device, DeviceFile, DeviceMode};
-use crate::error::DeviceResult;
+use crate::device::{CoreIdx, CoreStatus, Device, DeviceFile, DeviceInfo, DeviceMode};
+use crate::error::{DeviceError, DeviceResult};
+use crate::list::{collect_devices, filter_dev_files, list_devices_with, DevFile, MGMT_FILES};
+use crate::parse::parse_indices;
+use crate::sysfs::npu_mgmt;
+use crate::sysfs::npu_mgmt::PLATFORM_TYPE;
 
-#[derive(Copy, Clone)]
+#[derive(Clone)]
 pub struct DeviceConfig {
     arch: Arch,
     mode: DeviceMode,
     count: u8,
+    named: Option<Vec<(u8, Vec<u8>)>>,
 }
 
 impl DeviceConfig {
@@ -18,9 +28,45 @@ impl DeviceConfig {
             arch: Arch::Warboy,
             mode: DeviceMode::Single,
             count: 1,
+            named: None,
         };
         WarboyConfigBuilder(builder)
     }
+
+    /// Pins allocation to the specific device files named in `names` (e.g.
+    /// `"npu0pe0-1"`), rather than letting first-fit pick suitable cores.
+    /// The returned config still goes through the availability checks in
+    /// [`find_devices_in`].
+    pub fn named(names: &[&str]) -> DeviceResult<DeviceConfig> {
+        let named = names
+            .iter()
+            .map(|name| parse_indices(name))
+            .collect::<DeviceResult<Vec<_>>>()?;
+
+        Ok(DeviceConfig {
+            arch: Arch::Warboy,
+            mode: DeviceMode::Single,
+            count: named.len() as u8,
+            named: Some(named),
+        })
+    }
+
+    pub(crate) fn count(&self) -> u8 {
+        self.count
+    }
+
+    pub(crate) fn is_named(&self) -> bool {
+        self.named.is_some()
+    }
+
+    /// Returns a copy of this config restricted to `count`, keeping the
+    /// same arch/mode/named target. Used by `acquire` to ask for only the
+    /// slots still outstanding on a retry round.
+    pub(crate) fn with_count(&self, count: u8) -> DeviceConfig {
+        let mut config = self.clone();
+        config.count = count;
+        config
+    }
 }
 
 impl Default for DeviceConfig {
@@ -75,6 +121,92 @@ pub(crate) async fn expand_status(devices: Vec<Device>) -> DeviceResult<Vec<Devi
     Ok(new_devices)
 }
 
+pub async fn list_devices() -> DeviceResult<Vec<Device>> {
+    list_devices_with("/dev", "/sys").await
+}
+
+pub async fn find_devices(config: &DeviceConfig) -> DeviceResult<Vec<DeviceFile>> {
+    let devices = expand_status(list_devices().await?).await?;
+    find_devices_in(config, &devices)
+}
+
+/// Fetches a single device by its index, e.g. `1` for `npu1`.
+pub async fn get_device(idx: u8) -> DeviceResult<Device> {
+    get_device_with(idx, "/dev", "/sys").await
+}
+
+pub(crate) async fn get_device_with(idx: u8, devfs: &str, sysfs: &str) -> DeviceResult<Device> {
+    let paths = filter_dev_files(list_devfs(devfs).await?)?
+        .into_iter()
+        .find(|(i, _)| *i == idx)
+        .map(|(_, paths)| paths)
+        .ok_or_else(|| DeviceError::device_not_found(format!("npu{idx}")))?;
+
+    if !is_furiosa_device(idx, sysfs).await {
+        return Err(DeviceError::device_not_found(format!("npu{idx}")));
+    }
+
+    let mgmt_files = read_mgmt_files(sysfs, idx).await?;
+    let device_info = DeviceInfo::try_from(mgmt_files)?;
+    collect_devices(idx, device_info, paths)
+}
+
+/// Fetches a single device file by its name, e.g. `npu0`, `npu0pe0`, or
+/// `npu0pe0-1`.
+pub async fn get_device_file(name: &str) -> DeviceResult<DeviceFile> {
+    get_device_file_with(name, "/dev", "/sys").await
+}
+
+pub(crate) async fn get_device_file_with(
+    name: &str,
+    devfs: &str,
+    sysfs: &str,
+) -> DeviceResult<DeviceFile> {
+    let (idx, _cores) = parse_indices(name)?;
+    let device = get_device_with(idx, devfs, sysfs).await?;
+
+    device
+        .dev_files()
+        .iter()
+        .find(|dev_file| dev_file.filename() == name)
+        .cloned()
+        .ok_or_else(|| DeviceError::device_not_found(name))
+}
+
+async fn list_devfs<P: AsRef<Path>>(devfs: P) -> io::Result<Vec<DevFile>> {
+    let mut dev_files = Vec::new();
+
+    let mut entries = fs::read_dir(devfs).await?;
+    while let Some(entry) = entries.next_entry().await? {
+        dev_files.push(DevFile {
+            path: entry.path(),
+            file_type: entry.file_type().await?,
+        });
+    }
+
+    Ok(dev_files)
+}
+
+async fn is_furiosa_device(idx: u8, sysfs: &str) -> bool {
+    fs::read_to_string(npu_mgmt::path(sysfs, PLATFORM_TYPE, idx))
+        .await
+        .ok()
+        .filter(|c| npu_mgmt::is_furiosa_platform(c))
+        .is_some()
+}
+
+async fn read_mgmt_files(sysfs: &str, idx: u8) -> io::Result<HashMap<&'static str, String>> {
+    let mut mgmt_files: HashMap<&'static str, String> = HashMap::new();
+    for mgmt_file in MGMT_FILES {
+        let path = npu_mgmt::path(sysfs, mgmt_file, idx);
+        let contents = fs::read_to_string(&path).await.map(|s| s.trim().to_string())?;
+        if mgmt_files.insert(mgmt_file, contents).is_some() {
+            unreachable!("duplicate {} file at {}", mgmt_file, path.display());
+        }
+    }
+    Ok(mgmt_files)
+}
+
 pub(crate) fn find_devices_in(
     config: &DeviceConfig,
     devices: &[DeviceWithStatus],
@@ -93,6 +225,10 @@ pub(crate) fn find_devices_in(
         );
     }
 
+    if let Some(named) = &config.named {
+        return find_named_devices_in(named, devices, &mut allocated);
+    }
+
     let mut found: Vec<DeviceFile> = Vec::with_capacity(config.count.into());
 
     'outer: for _ in 0..config.count {
@@ -134,6 +270,157 @@ pub(crate) fn find_devices_in(
     Ok(found)
 }
 
+/// Resolves a `named` target list to the exact [`DeviceFile`]s it refers to,
+/// failing (by returning an empty vec, matching [`find_devices_in`]'s "not
+/// found" convention) if any named device/cores are absent or already taken.
+fn find_named_devices_in(
+    named: &[(u8, Vec<u8>)],
+    devices: &[DeviceWithStatus],
+    allocated: &mut HashMap<u8, HashSet<u8>>,
+) -> DeviceResult<Vec<DeviceFile>> {
+    let mut found = Vec::with_capacity(named.len());
+
+    for (device_idx, cores) in named {
+        let device = match devices.iter().find(|d| d.device_index() == *device_idx) {
+            Some(device) => device,
+            None => return Ok(vec![]),
+        };
+
+        let dev_file = match device
+            .dev_files()
+            .iter()
+            .find(|dev_file| dev_file.indices().iter().eq(cores.iter()))
+        {
+            Some(dev_file) => dev_file.clone(),
+            None => return Ok(vec![]),
+        };
+
+        // a multicore file (`npuN`) names no cores but claims all of them
+        let claimed: Vec<u8> = if dev_file.is_multicore() {
+            device.cores().to_vec()
+        } else {
+            cores.clone()
+        };
+
+        let used = allocated
+            .get_mut(device_idx)
+            .expect("allocated map is pre-populated for every device");
+        if claimed.iter().any(|core| used.contains(core)) {
+            return Ok(vec![]);
+        }
+
+        used.extend(claimed);
+        found.push(dev_file);
+    }
+
+    Ok(found)
+}
+
+/// A [`DeviceFile`] that has been opened and reserved for exclusive use.
+///
+/// Dropping an `OpenDeviceFile` closes the underlying handle, releasing the
+/// device back to other callers.
+pub struct OpenDeviceFile {
+    dev_file: DeviceFile,
+    #[allow(dead_code)]
+    file: File,
+}
+
+impl OpenDeviceFile {
+    pub fn filename(&self) -> &str {
+        self.dev_file.filename()
+    }
+
+    pub fn device_file(&self) -> &DeviceFile {
+        &self.dev_file
+    }
+}
+
+impl DeviceConfig {
+    /// Selects devices suitable for this config and opens each one as soon
+    /// as it's selected, turning [`find_devices`]'s advisory snapshot into a
+    /// real reservation. An `EBUSY` at open time means the core was claimed
+    /// by someone else between selection and open; rather than propagating
+    /// that as an error, selection simply retries against the remaining
+    /// candidates. A [`DeviceConfig::named`] config has no other candidates,
+    /// so an `EBUSY` on one of its files returns an empty vec.
+    ///
+    /// Follows the same all-or-nothing convention as [`find_devices`]/
+    /// [`find_devices_in`]: if the requested count can't be satisfied, an
+    /// empty vec is returned and anything opened along the way is released.
+    pub async fn acquire(&self) -> DeviceResult<Vec<OpenDeviceFile>> {
+        let mut devices = expand_status(list_devices().await?).await?;
+        let mut opened: Vec<OpenDeviceFile> = Vec::with_capacity(self.count() as usize);
+
+        while opened.len() < self.count() as usize {
+            let probe = if self.is_named() {
+                self.clone()
+            } else {
+                self.with_count((self.count() as usize - opened.len()) as u8)
+            };
+
+            let candidates = find_devices_in(&probe, &devices)?;
+            if candidates.is_empty() {
+                return Ok(Vec::new());
+            }
+
+            for dev_file in candidates {
+                mark_occupied(&mut devices, &dev_file)?;
+
+                match try_open(&dev_file).await {
+                    Ok(file) => {
+                        opened.push(OpenDeviceFile { dev_file, file });
+                    }
+                    Err(err) if err.raw_os_error() == Some(16) => {
+                        if self.is_named() {
+                            // a fixed named target has no fallback candidate
+                            return Ok(Vec::new());
+                        }
+                        // otherwise, loop around: find_devices_in will see
+                        // the refreshed (now-occupied) status and pick a
+                        // different candidate for this slot.
+                    }
+                    Err(err) => return Err(err.into()),
+                }
+            }
+        }
+
+        Ok(opened)
+    }
+}
+
+async fn try_open(dev_file: &DeviceFile) -> io::Result<File> {
+    OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(&dev_file.path)
+        .await
+}
+
+/// Marks `dev_file`'s cores as occupied in `devices`' status snapshot, so a
+/// subsequent [`find_devices_in`] call doesn't try to select it again.
+fn mark_occupied(devices: &mut [DeviceWithStatus], dev_file: &DeviceFile) -> DeviceResult<()> {
+    let (device_idx, _cores) = parse_indices(dev_file.filename())?;
+
+    if let Some(device) = devices
+        .iter_mut()
+        .find(|device| device.device_index() == device_idx)
+    {
+        let cores: Vec<CoreIdx> = if dev_file.is_multicore() {
+            device.cores().to_vec()
+        } else {
+            dev_file.indices().to_vec()
+        };
+        for core in cores {
+            device
+                .statuses
+                .insert(core, CoreStatus::Occupied(dev_file.to_string()));
+        }
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use crate::list::list_devices_with;
@@ -174,4 +461,77 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_find_named_devices() -> DeviceResult<()> {
+        // test directory contains 2 warboy NPUs
+        let devices = list_devices_with("test_data/test-0/dev", "test_data/test-0/sys").await?;
+        let devices_with_statuses = expand_status(devices).await?;
+
+        let config = DeviceConfig::named(&["npu0pe0-1", "npu1pe0"])?;
+        let found = find_devices_in(&config, &devices_with_statuses)?;
+        assert_eq!(found.len(), 2);
+        assert_eq!(found[0].filename(), "npu0pe0-1");
+        assert_eq!(found[1].filename(), "npu1pe0");
+
+        // requesting the same core twice should fail
+        let config = DeviceConfig::named(&["npu0pe0", "npu0pe0"])?;
+        let found = find_devices_in(&config, &devices_with_statuses)?;
+        assert_eq!(found, vec![]);
+
+        // a device that doesn't exist should fail
+        let config = DeviceConfig::named(&["npu9pe0"])?;
+        let found = find_devices_in(&config, &devices_with_statuses)?;
+        assert_eq!(found, vec![]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_device() -> DeviceResult<()> {
+        let device = get_device_with(0, "test_data/test-0/dev", "test_data/test-0/sys").await?;
+        assert_eq!(device.device_index(), 0);
+
+        // an index with no matching npu device should fail
+        assert!(get_device_with(9, "test_data/test-0/dev", "test_data/test-0/sys")
+            .await
+            .is_err());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_get_device_rejects_non_furiosa_platform() {
+        // npu2 has a /dev entry but its sysfs platform_type file doesn't
+        // identify it as a Furiosa device, mirroring the filter
+        // list_devices_with applies; get_device should reject it the same
+        // way rather than returning it.
+        assert!(get_device_with(2, "test_data/test-0/dev", "test_data/test-0/sys")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_device_file() -> DeviceResult<()> {
+        let dev_file =
+            get_device_file_with("npu0pe0-1", "test_data/test-0/dev", "test_data/test-0/sys")
+                .await?;
+        assert_eq!(dev_file.filename(), "npu0pe0-1");
+
+        // a device that doesn't exist should fail
+        assert!(
+            get_device_file_with("npu9pe0", "test_data/test-0/dev", "test_data/test-0/sys")
+                .await
+                .is_err()
+        );
+
+        // a core that doesn't exist on an otherwise valid device should fail
+        assert!(
+            get_device_file_with("npu0pe9", "test_data/test-0/dev", "test_data/test-0/sys")
+                .await
+                .is_err()
+        );
+
+        Ok(())
+    }
 }
@@ -0,0 +1,220 @@
+//! Hardware-monitoring (`hwmon`) sensor telemetry for NPU devices.
+//!
+//! The kernel driver registers each NPU under the standard Linux `hwmon`
+//! sysfs class. This module walks `<sysfs>/class/hwmon/hwmon*/`, looking for
+//! the entry whose `device` symlink resolves back to the NPU itself, then
+//! reads the paired `<type><n>_label`/`<type><n>_input` files for every
+//! sensor channel it finds. Channels are reported in whatever natural unit
+//! the `hwmon` convention implies (millidegrees/millivolts/microwatts are
+//! scaled down accordingly). Channels with no readable `_input` file are
+//! skipped, since a device may only expose a subset of them; a channel with
+//! no `_label` file is labelled with its channel name (e.g. `temp1`).
+
+use std::path::Path;
+
+use tokio::fs;
+
+use crate::device::Device;
+use crate::error::DeviceResult;
+
+/// A single sensor channel read from the `hwmon` sysfs tree.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SensorReading {
+    pub label: String,
+    pub kind: SensorKind,
+    pub value: SensorValue,
+}
+
+/// The physical quantity a sensor channel measures, taken from the `hwmon`
+/// file name prefix (`temp`, `power`, `in`, `curr`).
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum SensorKind {
+    Temperature,
+    Power,
+    Voltage,
+    Current,
+}
+
+impl SensorKind {
+    fn from_prefix(prefix: &str) -> Option<Self> {
+        match prefix {
+            "temp" => Some(SensorKind::Temperature),
+            "power" => Some(SensorKind::Power),
+            "in" => Some(SensorKind::Voltage),
+            "curr" => Some(SensorKind::Current),
+            _ => None,
+        }
+    }
+}
+
+/// A sensor's value, scaled from the raw `hwmon` integer (millidegrees,
+/// microwatts, millivolts, milliamperes) into its natural unit.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SensorValue {
+    Celsius(f64),
+    Watts(f64),
+    Volts(f64),
+    Amperes(f64),
+}
+
+impl SensorValue {
+    fn scaled(kind: SensorKind, raw: i64) -> Self {
+        match kind {
+            SensorKind::Temperature => SensorValue::Celsius(raw as f64 / 1_000.0),
+            SensorKind::Power => SensorValue::Watts(raw as f64 / 1_000_000.0),
+            SensorKind::Voltage => SensorValue::Volts(raw as f64 / 1_000.0),
+            SensorKind::Current => SensorValue::Amperes(raw as f64 / 1_000.0),
+        }
+    }
+}
+
+impl Device {
+    /// Reads live sensor telemetry (temperature, power, voltage, current)
+    /// for this device from the Linux `hwmon` sysfs tree.
+    pub async fn fetch_hwmon(&self) -> DeviceResult<Vec<SensorReading>> {
+        fetch_hwmon_with("/sys", self.device_index()).await
+    }
+}
+
+pub(crate) async fn fetch_hwmon_with(sysfs: &str, idx: u8) -> DeviceResult<Vec<SensorReading>> {
+    let hwmon_class = Path::new(sysfs).join("class/hwmon");
+
+    let mut entries = match fs::read_dir(&hwmon_class).await {
+        Ok(entries) => entries,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let mut readings = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let hwmon_dir = entry.path();
+        if belongs_to_device(&hwmon_dir, idx).await {
+            readings.extend(read_channels(&hwmon_dir).await?);
+        }
+    }
+
+    Ok(readings)
+}
+
+/// Resolves `<hwmon_dir>/device` and checks that it points back at `npu{idx}`.
+async fn belongs_to_device(hwmon_dir: &Path, idx: u8) -> bool {
+    let resolved = match fs::canonicalize(hwmon_dir.join("device")).await {
+        Ok(path) => path,
+        Err(_) => return false,
+    };
+
+    resolved
+        .file_name()
+        .and_then(|name| name.to_str())
+        .map(|name| name == format!("npu{idx}"))
+        .unwrap_or(false)
+}
+
+async fn read_channels(hwmon_dir: &Path) -> DeviceResult<Vec<SensorReading>> {
+    let mut entries = match fs::read_dir(hwmon_dir).await {
+        Ok(entries) => entries,
+        Err(_) => return Ok(Vec::new()),
+    };
+
+    let mut readings = Vec::new();
+    while let Some(entry) = entries.next_entry().await? {
+        let file_name = entry.file_name();
+        let Some(file_name) = file_name.to_str() else {
+            continue;
+        };
+        let Some(channel) = file_name.strip_suffix("_input") else {
+            continue;
+        };
+        let Some((prefix, _)) = split_channel(channel) else {
+            continue;
+        };
+        let Some(kind) = SensorKind::from_prefix(prefix) else {
+            continue;
+        };
+
+        let raw = match fs::read_to_string(hwmon_dir.join(file_name)).await {
+            Ok(contents) => match contents.trim().parse::<i64>() {
+                Ok(raw) => raw,
+                Err(_) => continue,
+            },
+            Err(_) => continue,
+        };
+
+        let label = fs::read_to_string(hwmon_dir.join(format!("{channel}_label")))
+            .await
+            .map(|contents| contents.trim().to_string())
+            .unwrap_or_else(|_| channel.to_string());
+
+        readings.push(SensorReading {
+            label,
+            kind,
+            value: SensorValue::scaled(kind, raw),
+        });
+    }
+
+    Ok(readings)
+}
+
+/// Splits a channel name like `temp1` into its type prefix and index, e.g.
+/// `("temp", "1")`.
+fn split_channel(channel: &str) -> Option<(&str, &str)> {
+    let split_at = channel.find(|c: char| c.is_ascii_digit())?;
+    Some((&channel[..split_at], &channel[split_at..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_fetch_hwmon_with() -> DeviceResult<()> {
+        // test directory contains an npu0 with a temperature and a power
+        // channel, and an npu1 with no hwmon entry at all
+        let readings = fetch_hwmon_with("test_data/test-0/sys", 0).await?;
+
+        let temp = readings
+            .iter()
+            .find(|r| r.kind == SensorKind::Temperature)
+            .expect("npu0 should expose a temperature channel");
+        assert_eq!(temp.value, SensorValue::Celsius(42.0));
+
+        let power = readings
+            .iter()
+            .find(|r| r.kind == SensorKind::Power)
+            .expect("npu0 should expose a power channel");
+        assert_eq!(power.value, SensorValue::Watts(1.5));
+
+        // a device with no hwmon entry just yields no readings
+        assert!(fetch_hwmon_with("test_data/test-0/sys", 1).await?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_split_channel() {
+        assert_eq!(split_channel("temp1"), Some(("temp", "1")));
+        assert_eq!(split_channel("power1"), Some(("power", "1")));
+        assert_eq!(split_channel("in0"), Some(("in", "0")));
+        assert_eq!(split_channel("curr1"), Some(("curr", "1")));
+        assert_eq!(split_channel("noindex"), None);
+    }
+
+    #[test]
+    fn test_sensor_value_scaled() {
+        assert_eq!(
+            SensorValue::scaled(SensorKind::Temperature, 42_000),
+            SensorValue::Celsius(42.0)
+        );
+        assert_eq!(
+            SensorValue::scaled(SensorKind::Power, 1_500_000),
+            SensorValue::Watts(1.5)
+        );
+        assert_eq!(
+            SensorValue::scaled(SensorKind::Voltage, 900),
+            SensorValue::Volts(0.9)
+        );
+        assert_eq!(
+            SensorValue::scaled(SensorKind::Current, 2_500),
+            SensorValue::Amperes(2.5)
+        );
+    }
+}
@@ -0,0 +1,68 @@
+//! Parsing for NPU device-file names (`npuN`, `npuNpeM`, `npuNpeA-B`).
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use crate::error::{DeviceError, DeviceResult};
+
+static DEVICE_FILE_PATTERN: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^npu(?P<device_id>\d+)((?:pe)(?P<start_core>\d+)(-(?P<end_core>\d+))?)?$")
+        .expect("DEVICE_FILE_PATTERN should be a valid regex")
+});
+
+/// Parses a device-file name into its device index and the (possibly empty)
+/// list of core indices it names: `npuN` yields no cores, `npuNpeM` yields
+/// `[M]`, and `npuNpeA-B` expands to the inclusive range `[A..=B]`.
+pub(crate) fn parse_indices(name: &str) -> DeviceResult<(u8, Vec<u8>)> {
+    let captures = DEVICE_FILE_PATTERN
+        .captures(name)
+        .ok_or_else(|| DeviceError::unrecognized_file(name))?;
+
+    let device_id = parse_component(&captures["device_id"], name)?;
+
+    let cores = match (captures.name("start_core"), captures.name("end_core")) {
+        (None, _) => Vec::new(),
+        (Some(start), None) => vec![parse_component(start.as_str(), name)?],
+        (Some(start), Some(end)) => {
+            let start = parse_component(start.as_str(), name)?;
+            let end = parse_component(end.as_str(), name)?;
+            if end < start {
+                return Err(DeviceError::unrecognized_file(name));
+            }
+            (start..=end).collect()
+        }
+    };
+
+    Ok((device_id, cores))
+}
+
+fn parse_component(text: &str, name: &str) -> DeviceResult<u8> {
+    text.parse().map_err(|_| DeviceError::unrecognized_file(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_indices() {
+        assert_eq!(parse_indices("npu0").unwrap(), (0, vec![]));
+        assert_eq!(parse_indices("npu0pe0").unwrap(), (0, vec![0]));
+        assert_eq!(parse_indices("npu0pe0-1").unwrap(), (0, vec![0, 1]));
+        assert_eq!(parse_indices("npu1pe2-4").unwrap(), (1, vec![2, 3, 4]));
+    }
+
+    #[test]
+    fn test_parse_indices_rejects_unrecognized_names() {
+        assert!(parse_indices("npu").is_err());
+        assert!(parse_indices("pe0").is_err());
+        assert!(parse_indices("npu0pe").is_err());
+        assert!(parse_indices("npu0xyz").is_err());
+    }
+
+    #[test]
+    fn test_parse_indices_rejects_reversed_ranges() {
+        assert!(parse_indices("npu0pe4-2").is_err());
+        assert!(parse_indices("npu0pe1-0").is_err());
+    }
+}